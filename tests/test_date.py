@@ -1,13 +1,25 @@
 import pickle
+import re
+import sys
 import weakref
+from calendar import isleap
 from copy import copy, deepcopy
-from datetime import date as py_date
+from datetime import (
+    date as py_date,
+    datetime as py_datetime,
+    time as py_time,
+    timedelta,
+)
 from itertools import chain, product
 
 import pytest
+from hypothesis import assume, given
+from hypothesis.strategies import integers
 
 from whenever import (
     FRIDAY,
+    MAX_YEAR,
+    MIN_YEAR,
     MONDAY,
     SATURDAY,
     SUNDAY,
@@ -16,13 +28,18 @@ from whenever import (
     WEDNESDAY,
     Date,
     DateDelta,
+    DateTimeDelta,
     NaiveDateTime,
     Time,
+    TimeDelta,
     days,
+    enable_date_cache,
 )
 
 from .common import AlwaysEqual, AlwaysLarger, AlwaysSmaller, NeverEqual
 
+ordinals = integers(1, py_date.max.toordinal())
+
 
 def test_basics():
     d = Date(2021, 1, 2)
@@ -31,6 +48,65 @@ def test_basics():
     assert d.day == 2
 
 
+@pytest.mark.parametrize(
+    "args, message",
+    [
+        ((0, 1, 1), "year 0 is out of range (1..9999)"),
+        ((10_000, 1, 1), "year 10000 is out of range (1..9999)"),
+        ((2021, 0, 1), "month 0 is out of range (1..12)"),
+        ((2021, 13, 1), "month 13 is out of range (1..12)"),
+        ((2021, 4, 31), "day 31 is out of range for month 4 (max 30)"),
+        ((2021, 2, 29), "day 29 is out of range for month 2 (max 28)"),
+        ((2020, 2, 30), "day 30 is out of range for month 2 (max 29)"),
+        ((2021, 1, 0), "day 0 is out of range for month 1 (max 31)"),
+    ],
+)
+def test_invalid(args, message):
+    with pytest.raises(ValueError, match=re.escape(message)):
+        Date(*args)
+
+
+def test_invalid_type():
+    with pytest.raises(TypeError):
+        Date("2021", 1, 1)  # type: ignore[arg-type]
+
+
+def test_with_component():
+    d = Date(2020, 1, 31)
+    assert d.with_year(2021) == Date(2021, 1, 31)
+    assert d.with_month(3) == Date(2020, 3, 31)
+    assert d.with_day(2) == Date(2020, 1, 2)
+    assert Date(2020, 2, 29).with_year(2024) == Date(2024, 2, 29)
+
+
+def test_with_component_unchanged():
+    # Since dates are immutable, there's no need for a new object
+    d = Date(2020, 1, 31)
+    assert d.with_year(2020) is d
+    assert d.with_month(1) is d
+    assert d.with_day(31) is d
+    assert d.with_year(2021) is not d
+    # other types are still passed to the constructor
+    with pytest.raises(TypeError):
+        d.with_year(2020.0)  # type: ignore[arg-type]
+
+
+@pytest.mark.parametrize(
+    "d, method, value, message",
+    [
+        (Date(2020, 2, 29), "with_year", 2021, "day 29 is out of range"),
+        (Date(2020, 1, 1), "with_year", 0, "year 0 is out of range"),
+        (Date(2020, 1, 31), "with_month", 4, "day 31 is out of range"),
+        (Date(2020, 1, 31), "with_month", 13, "month 13 is out of range"),
+        (Date(2020, 2, 1), "with_day", 30, "day 30 is out of range"),
+        (Date(2020, 2, 1), "with_day", 0, "day 0 is out of range"),
+    ],
+)
+def test_with_component_invalid(d, method, value, message):
+    with pytest.raises(ValueError, match=message):
+        getattr(d, method)(value)
+
+
 def test_canonical_format():
     d = Date(2021, 1, 2)
     assert str(d) == "2021-01-02"
@@ -67,10 +143,80 @@ class TestFromCanonicalFormat:
         ):
             Date.from_canonical_format(s)
 
+    @pytest.mark.parametrize(
+        "s", ["2021-01-0é", "2021-０1-02", "2021-01-02🕐"]
+    )
+    def test_non_ascii_in_error(self, s):
+        with pytest.raises(ValueError) as exc_info:
+            Date.from_canonical_format(s)
+        assert repr(s) in str(exc_info.value)
+
+
+class TestDateCache:
+    def test_disabled_by_default(self):
+        a = Date.from_canonical_format("2021-01-02")
+        b = Date.from_canonical_format("2021-01-02")
+        assert a == b
+        assert a is not b
+
+    def test_enabled(self):
+        enable_date_cache(2)
+        try:
+            a = Date.from_canonical_format("2021-01-02")
+            assert Date.from_canonical_format("2021-01-02") is a
+            assert Date.from_common_iso8601("2021-01-02") is a
+            # results are the same as without the cache
+            assert a == Date(2021, 1, 2)
+            b = Date.from_canonical_format("2021-01-03")
+            assert b == Date(2021, 1, 3)
+            # the least recently used date is evicted
+            Date.from_canonical_format("2021-01-04")
+            assert Date.from_canonical_format("2021-01-03") is b
+            assert Date.from_canonical_format("2021-01-02") is not a
+        finally:
+            enable_date_cache(0)
+        assert Date.from_canonical_format("2021-01-03") is not b
+
+    def test_errors_not_cached(self):
+        enable_date_cache(8)
+        try:
+            for _ in range(2):
+                with pytest.raises(ValueError, match="canonical format"):
+                    Date.from_canonical_format("2021-02-30")
+                with pytest.raises(ValueError, match="canonical format"):
+                    Date.from_canonical_format("2021-W12-3")
+        finally:
+            enable_date_cache(0)
+
+    def test_subclass_not_cached(self):
+        class Sub(Date):
+            pass
+
+        enable_date_cache(8)
+        try:
+            a = Date.from_canonical_format("2021-01-02")
+            b = Sub.from_canonical_format("2021-01-02")
+            assert type(b) is Sub
+            assert b is not a
+            assert Date.from_canonical_format("2021-01-02") is a
+        finally:
+            enable_date_cache(0)
+
+    def test_invalid_size(self):
+        with pytest.raises(ValueError, match="non-negative"):
+            enable_date_cache(-1)
+
 
 def test_at():
     d = Date(2021, 1, 2)
     assert d.at(Time(3, 4, 5)) == NaiveDateTime(2021, 1, 2, 3, 4, 5)
+    assert d.at(Time()) == NaiveDateTime(2021, 1, 2)
+
+
+@pytest.mark.parametrize("t", [None, "12:00:00", Date(2021, 1, 2)])
+def test_at_invalid_type(t):
+    with pytest.raises(TypeError, match="Expected Time"):
+        Date(2021, 1, 2).at(t)  # type: ignore[arg-type]
 
 
 def test_repr():
@@ -96,6 +242,23 @@ def test_eq():
     assert hash(d) == hash(same)
 
 
+@pytest.mark.skipif(
+    sys.maxsize < 2**63 - 1, reason="hash values differ on 32-bit platforms"
+)
+@pytest.mark.parametrize(
+    "d, expected",
+    [
+        (Date(2021, 1, 2), 9_209_690_449_975_025_898),
+        (Date(1, 1, 1), 5_750_192_569_890_809_213),
+        (Date(9999, 12, 31), -3_809_057_329_291_756_577),
+    ],
+)
+def test_hash_deterministic(d, expected):
+    # Unlike str and bytes, hashing dates isn't randomized per process,
+    # so these values are the same across runs and PYTHONHASHSEED values
+    assert hash(d) == expected
+
+
 def test_comparison():
     d = Date(2021, 5, 10)
     same = Date(2021, 5, 10)
@@ -127,6 +290,70 @@ def test_comparison():
     assert d > AlwaysSmaller()
 
 
+class TestCompareTuple:
+    def test_eq(self):
+        d = Date(2021, 5, 10)
+        assert d == (2021, 5, 10)
+        assert (2021, 5, 10) == d
+        assert not d != (2021, 5, 10)
+        assert d != (2021, 5, 11)
+        assert (2021, 5, 11) != d
+        assert hash(d) == hash((2021, 5, 10))
+        assert {d: 1}[(2021, 5, 10)] == 1
+
+    def test_ordering(self):
+        d = Date(2021, 5, 10)
+        assert d < (2021, 5, 11)
+        assert d <= (2021, 5, 10)
+        assert d > (2020, 12, 31)
+        assert d >= (2021, 5, 10)
+        assert (2021, 5, 11) > d
+        assert not (2021, 5, 10) < d
+        assert sorted([(2022, 1, 1), d, (2020, 1, 1)]) == [
+            (2020, 1, 1),
+            d,
+            (2022, 1, 1),
+        ]
+
+    @pytest.mark.parametrize(
+        "other",
+        [
+            (2021, 5),
+            (2021, 5, 10, 0),
+            (2021, 5, 10.0),
+            ("2021", 5, 10),
+            [2021, 5, 10],
+        ],
+    )
+    def test_invalid(self, other):
+        d = Date(2021, 5, 10)
+        assert d != other
+        assert not d == other
+        with pytest.raises(TypeError):
+            d < other  # type: ignore[operator]
+        with pytest.raises(TypeError):
+            d >= other  # type: ignore[operator]
+
+
+@pytest.mark.parametrize("other", [None, py_date(2021, 5, 10), "2021-05-10"])
+def test_compare_other_types(other):
+    d = Date(2021, 5, 10)
+    assert (d == other) is False
+    assert (d != other) is True
+    assert (other == d) is False
+    assert (other != d) is True
+    with pytest.raises(TypeError):
+        d < other  # type: ignore[operator]
+    with pytest.raises(TypeError):
+        d <= other  # type: ignore[operator]
+    with pytest.raises(TypeError):
+        d > other  # type: ignore[operator]
+    with pytest.raises(TypeError):
+        d >= other  # type: ignore[operator]
+    with pytest.raises(TypeError):
+        other < d  # type: ignore[operator]
+
+
 @pytest.mark.parametrize(
     "d, kwargs, expected",
     [
@@ -151,10 +378,243 @@ def test_add(d, kwargs, expected):
     assert d + DateDelta(**kwargs) == expected
 
 
+class TestPyTimedelta:
+    def test_add(self):
+        assert Date(2021, 1, 31) + timedelta(days=1) == Date(2021, 2, 1)
+        assert Date(2021, 1, 31) + timedelta(weeks=-1) == Date(2021, 1, 24)
+
+    def test_subtract(self):
+        assert Date(2021, 3, 1) - timedelta(days=1) == Date(2021, 2, 28)
+        assert Date(2021, 1, 24) - timedelta(weeks=-1) == Date(2021, 1, 31)
+
+    @pytest.mark.parametrize(
+        "td",
+        [
+            timedelta(hours=1),
+            timedelta(days=1, seconds=1),
+            timedelta(microseconds=-1),
+        ],
+    )
+    def test_time_part_not_allowed(self, td):
+        with pytest.raises(ValueError, match="whole days"):
+            Date(2021, 1, 1) + td
+        with pytest.raises(ValueError, match="whole days"):
+            Date(2021, 1, 1) - td
+
+
+def test_resolution():
+    assert Date.RESOLUTION == DateDelta(days=1)
+    d = Date(2021, 1, 2)
+    assert d + Date.RESOLUTION == Date(2021, 1, 3)
+    assert Date(2021, 1, 3) - d == Date.RESOLUTION
+
+
+class TestDaysSinceEpoch:
+    def test_epoch(self):
+        assert Date.UNIX_EPOCH == Date(1970, 1, 1)
+        assert Date.UNIX_EPOCH.days_since_epoch() == 0
+        assert Date.from_days_since_epoch(0) == Date.UNIX_EPOCH
+
+    @pytest.mark.parametrize(
+        "d, n",
+        [
+            (Date(2021, 1, 2), 18_629),
+            (Date(1969, 12, 31), -1),
+            (Date(1, 1, 1), -719_162),
+            (Date(9999, 12, 31), 2_932_896),
+        ],
+    )
+    def test_roundtrip(self, d, n):
+        assert d.days_since_epoch() == n
+        assert Date.from_days_since_epoch(n) == d
+
+    def test_matches_utc_timestamp(self):
+        d = Date(2020, 8, 15)
+        assert d.days_since_epoch() * 86_400 == (
+            d.at(Time()).assume_utc().timestamp()
+        )
+
+    @pytest.mark.parametrize("n", [-719_163, 2_932_897, 1 << 80])
+    def test_out_of_range(self, n):
+        with pytest.raises(ValueError, match="out of range"):
+            Date.from_days_since_epoch(n)
+
+
+class TestFromYearDay:
+    @pytest.mark.parametrize(
+        "year, day, expected",
+        [
+            (2021, 1, Date(2021, 1, 1)),
+            (2021, 32, Date(2021, 2, 1)),
+            (2021, 60, Date(2021, 3, 1)),
+            (2020, 60, Date(2020, 2, 29)),
+            (2021, 365, Date(2021, 12, 31)),
+            (2020, 366, Date(2020, 12, 31)),
+            (1, 1, Date(1, 1, 1)),
+            (9999, 365, Date(9999, 12, 31)),
+        ],
+    )
+    def test_valid(self, year, day, expected):
+        assert Date.from_year_day(year, day) == expected
+        assert expected.day_of_year() == day
+
+    @pytest.mark.parametrize(
+        "year, day, msg",
+        [
+            (2021, 366, "day 366 is out of range for year 2021 (max 365)"),
+            (2020, 367, "day 367 is out of range for year 2020 (max 366)"),
+            (1900, 366, "day 366 is out of range for year 1900 (max 365)"),
+            (2021, 0, "day 0 is out of range"),
+            (2021, -1, "day -1 is out of range"),
+            (0, 1, "year 0 is out of range"),
+            (10_000, 1, "year 10000 is out of range"),
+        ],
+    )
+    def test_invalid(self, year, day, msg):
+        with pytest.raises(ValueError, match=re.escape(msg)):
+            Date.from_year_day(year, day)
+
+    @given(integers(MIN_YEAR, MAX_YEAR), integers(1, 366))
+    def test_roundtrip(self, year, day):
+        assume(day <= 365 or isleap(year))
+        d = Date.from_year_day(year, day)
+        assert d.year == year
+        assert d.day_of_year() == day
+
+
+class TestPacked:
+    @pytest.mark.parametrize(
+        "d",
+        [
+            Date(1, 1, 1),
+            Date(2021, 1, 2),
+            Date(2020, 2, 29),
+            Date(9999, 12, 31),
+        ],
+    )
+    def test_roundtrip(self, d):
+        assert Date.from_packed(d.to_packed()) == d
+
+    def test_layout(self):
+        assert Date(2021, 1, 2).to_packed() == 0x07E5_01_02
+        assert Date.from_packed(0x07E5_0C_1F) == Date(2021, 12, 31)
+
+    def test_sorts_like_dates(self):
+        dates = [Date(2021, 1, 2), Date(2020, 12, 31), Date(2021, 1, 1)]
+        assert sorted(dates, key=Date.to_packed) == sorted(dates)
+
+    @pytest.mark.parametrize(
+        "n",
+        [
+            0,
+            -1,
+            0x07E5_00_01,  # month 0
+            0x07E5_0D_01,  # month 13
+            0x07E5_02_1E,  # February 30
+            0x07E5_01_00,  # day 0
+            10_000 << 16 | 1 << 8 | 1,  # year 10000
+            1 << 80,
+        ],
+    )
+    def test_invalid(self, n):
+        with pytest.raises(ValueError, match="Invalid packed date"):
+            Date.from_packed(n)
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            Date.from_packed(132_448_514.0)  # type: ignore[arg-type]
+
+
+def test_add_invalid():
+    with pytest.raises(TypeError, match="unsupported operand"):
+        Date(2021, 1, 1) + 1  # type: ignore[operator]
+
+
 def test_from_py_date():
     assert Date.from_py_date(py_date(2021, 1, 2)) == Date(2021, 1, 2)
 
 
+def test_py_date():
+    assert Date(2021, 1, 2).py_date() == py_date(2021, 1, 2)
+    assert type(Date(2021, 1, 2).py_date()) is py_date
+
+
+class TestToPyDatetime:
+    def test_default_midnight(self):
+        dt = Date(2021, 1, 2).to_py_datetime()
+        assert dt == py_datetime(2021, 1, 2)
+        assert dt.tzinfo is None
+        assert dt == py_datetime.combine(
+            Date(2021, 1, 2).py_date(), py_time()
+        )
+
+    def test_time_components(self):
+        assert Date(2021, 1, 2).to_py_datetime(
+            13, 30, 5, 999_999
+        ) == py_datetime(2021, 1, 2, 13, 30, 5, 999_999)
+        assert Date(9999, 12, 31).to_py_datetime(
+            hour=23, minute=59, second=59, microsecond=999_999
+        ) == py_datetime(9999, 12, 31, 23, 59, 59, 999_999)
+
+    @pytest.mark.parametrize(
+        "kwargs, message",
+        [
+            (dict(hour=24), "hour"),
+            (dict(hour=-1), "hour"),
+            (dict(minute=60), "minute"),
+            (dict(second=60), "second"),
+            (dict(microsecond=1_000_000), "microsecond"),
+        ],
+    )
+    def test_invalid(self, kwargs, message):
+        with pytest.raises(ValueError, match=message):
+            Date(2021, 1, 2).to_py_datetime(**kwargs)
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError):
+            Date(2021, 1, 2).to_py_datetime("1")  # type: ignore[arg-type]
+
+
+class TestFromOrdinals:
+    def test_valid(self):
+        assert Date.from_ordinals([1, 737_792, 3_652_059]) == [
+            Date(1, 1, 1),
+            Date(2021, 1, 2),
+            Date(9999, 12, 31),
+        ]
+        assert Date.from_ordinals(iter(range(737_792, 737_795))) == [
+            Date(2021, 1, 2),
+            Date(2021, 1, 3),
+            Date(2021, 1, 4),
+        ]
+        assert Date.from_ordinals([]) == []
+
+    @pytest.mark.parametrize(
+        "ordinals, index",
+        [
+            ([0], 0),
+            ([1, 2, -1, 0], 2),
+            ([1, 3_652_060], 1),
+            ([1, 1 << 64], 1),
+        ],
+    )
+    def test_out_of_range(self, ordinals, index):
+        with pytest.raises(ValueError, match=f"index {index} "):
+            Date.from_ordinals(ordinals)
+
+    @pytest.mark.parametrize(
+        "ordinals, index, msg",
+        [
+            ([1, "2"], 1, "got str"),
+            ([1, 2.0], 1, "got float"),
+            ([None, 1], 0, "got NoneType"),
+        ],
+    )
+    def test_wrong_type(self, ordinals, index, msg):
+        with pytest.raises(TypeError, match=f"index {index}, {msg}"):
+            Date.from_ordinals(ordinals)
+
+
 @pytest.mark.parametrize(
     "d, kwargs, expected",
     [
@@ -183,6 +643,39 @@ def test_subtract(d, kwargs, expected):
     assert d - DateDelta(**kwargs) == expected
 
 
+@pytest.mark.parametrize(
+    "d, expected",
+    [
+        (Date(2021, 1, 2), Date(2021, 1, 3)),
+        (Date(2021, 1, 31), Date(2021, 2, 1)),
+        (Date(2020, 2, 28), Date(2020, 2, 29)),
+        (Date(2021, 2, 28), Date(2021, 3, 1)),
+        (Date(2021, 12, 31), Date(2022, 1, 1)),
+        (Date(1, 1, 1), Date(1, 1, 2)),
+        (Date(9999, 12, 30), Date(9999, 12, 31)),
+    ],
+)
+def test_tomorrow_and_yesterday(d, expected):
+    assert d.tomorrow() == expected
+    assert expected.yesterday() == d
+
+
+def test_tomorrow_and_yesterday_out_of_range():
+    with pytest.raises(ValueError, match="no date after 9999-12-31"):
+        Date(9999, 12, 31).tomorrow()
+    with pytest.raises(ValueError, match="no date before 0001-01-01"):
+        Date(1, 1, 1).yesterday()
+
+
+def test_succ_or_none_and_pred_or_none():
+    assert Date(2021, 12, 31).succ_or_none() == Date(2022, 1, 1)
+    assert Date(2022, 1, 1).pred_or_none() == Date(2021, 12, 31)
+    assert Date(9999, 12, 30).succ_or_none() == Date(9999, 12, 31)
+    assert Date(1, 1, 2).pred_or_none() == Date(1, 1, 1)
+    assert Date(9999, 12, 31).succ_or_none() is None
+    assert Date(1, 1, 1).pred_or_none() is None
+
+
 _EXAMPLE_DATES = [
     *chain.from_iterable(
         [
@@ -326,6 +819,22 @@ def test_subtract_invalid():
         Date(2021, 1, 1) - "2021-01-01"  # type: ignore[operator]
 
 
+@pytest.mark.parametrize(
+    "other",
+    [
+        1,
+        DateDelta(days=1),
+        timedelta(days=1),
+        TimeDelta(hours=1),
+        DateTimeDelta(days=1),
+        py_date(2021, 1, 1),
+    ],
+)
+def test_reflected_subtract_invalid(other):
+    with pytest.raises(TypeError, match="unsupported operand"):
+        other - Date(2021, 1, 1)  # type: ignore[operator]
+
+
 def test_day_of_week():
     d = Date(2021, 1, 2)
     assert d.day_of_week() == SATURDAY
@@ -337,6 +846,604 @@ def test_day_of_week():
     assert Date(2021, 1, 8).day_of_week() == FRIDAY
 
 
+def test_is_weekend():
+    assert Date(2021, 1, 2).is_weekend()
+    assert Date(2021, 1, 3).is_weekend()
+    for day in range(4, 9):
+        assert not Date(2021, 1, day).is_weekend()
+
+
+@pytest.mark.parametrize(
+    "year, century, decade",
+    [
+        (1, 1, 0),
+        (9, 1, 0),
+        (10, 1, 10),
+        (100, 1, 100),
+        (101, 2, 100),
+        (1999, 20, 1990),
+        (2000, 20, 2000),
+        (2001, 21, 2000),
+        (2021, 21, 2020),
+        (9999, 100, 9990),
+    ],
+)
+def test_century_and_decade(year, century, decade):
+    d = Date(year, 6, 15)
+    assert d.century() == century
+    assert d.decade() == decade
+
+
+@pytest.mark.parametrize(
+    "d, weekday, weekday_abbr",
+    [
+        (Date(2021, 1, 4), "Monday", "Mon"),
+        (Date(2021, 1, 5), "Tuesday", "Tue"),
+        (Date(2021, 1, 6), "Wednesday", "Wed"),
+        (Date(2021, 1, 7), "Thursday", "Thu"),
+        (Date(2021, 1, 8), "Friday", "Fri"),
+        (Date(2021, 1, 9), "Saturday", "Sat"),
+        (Date(2021, 1, 10), "Sunday", "Sun"),
+    ],
+)
+def test_weekday_name(d, weekday, weekday_abbr):
+    assert d.weekday_name() == weekday
+    assert d.weekday_abbr() == weekday_abbr
+
+
+def test_month_name():
+    names = [Date(2021, m, 1).month_name() for m in range(1, 13)]
+    assert names == [
+        "January",
+        "February",
+        "March",
+        "April",
+        "May",
+        "June",
+        "July",
+        "August",
+        "September",
+        "October",
+        "November",
+        "December",
+    ]
+    assert [Date(2021, m, 28).month_abbr() for m in range(1, 13)] == [
+        name[:3] for name in names
+    ]
+
+
+class TestAddBusinessDays:
+    @pytest.mark.parametrize(
+        "d, n, expected",
+        [
+            # Friday
+            (Date(2021, 12, 24), 1, Date(2021, 12, 27)),
+            (Date(2021, 12, 24), 5, Date(2021, 12, 31)),
+            (Date(2021, 12, 24), 6, Date(2022, 1, 3)),
+            (Date(2021, 12, 24), -1, Date(2021, 12, 23)),
+            (Date(2021, 12, 24), -5, Date(2021, 12, 17)),
+            # Monday
+            (Date(2021, 12, 27), -1, Date(2021, 12, 24)),
+            (Date(2021, 12, 27), -6, Date(2021, 12, 17)),
+            (Date(2021, 12, 27), 4, Date(2021, 12, 31)),
+            # Wednesday
+            (Date(2021, 12, 22), 3, Date(2021, 12, 27)),
+            (Date(2021, 12, 22), -3, Date(2021, 12, 17)),
+            (Date(2021, 12, 22), 260, Date(2022, 12, 21)),
+            # Saturday and Sunday
+            (Date(2021, 12, 25), 1, Date(2021, 12, 27)),
+            (Date(2021, 12, 26), 1, Date(2021, 12, 27)),
+            (Date(2021, 12, 25), -1, Date(2021, 12, 24)),
+            (Date(2021, 12, 26), -1, Date(2021, 12, 24)),
+            (Date(2021, 12, 26), 6, Date(2022, 1, 3)),
+            (Date(2021, 12, 25), -6, Date(2021, 12, 17)),
+        ],
+    )
+    def test_valid(self, d, n, expected):
+        assert d.add_business_days(n) == expected
+
+    @pytest.mark.parametrize(
+        "d", [Date(2021, 12, 24), Date(2021, 12, 25), Date(2021, 12, 26)]
+    )
+    def test_zero(self, d):
+        assert d.add_business_days(0) is d
+
+    def test_matches_iteration(self):
+        start = Date(2021, 12, 20)
+        for offset in range(7):
+            d = start.add(days=offset)
+            forward = d
+            backward = d
+            for n in range(1, 15):
+                forward = forward.add(days=1)
+                while forward.is_weekend():
+                    forward = forward.add(days=1)
+                backward = backward.subtract(days=1)
+                while backward.is_weekend():
+                    backward = backward.subtract(days=1)
+                assert d.add_business_days(n) == forward
+                assert d.add_business_days(-n) == backward
+                # the range includes the start, but not the end
+                assert d.business_days_between(forward.add(days=1)) == (
+                    n if d.is_weekend() else n + 1
+                )
+
+    def test_next_and_previous(self):
+        assert Date(2021, 12, 23).next_business_day() == Date(2021, 12, 24)
+        assert Date(2021, 12, 24).next_business_day() == Date(2021, 12, 27)
+        assert Date(2021, 12, 25).next_business_day() == Date(2021, 12, 27)
+        assert Date(2021, 12, 27).previous_business_day() == (
+            Date(2021, 12, 24)
+        )
+        assert Date(2021, 12, 26).previous_business_day() == (
+            Date(2021, 12, 24)
+        )
+        assert Date(2021, 12, 22).previous_business_day() == (
+            Date(2021, 12, 21)
+        )
+
+    def test_out_of_range(self):
+        # 9999-12-31 is a Friday, 0001-01-01 a Monday
+        with pytest.raises(ValueError, match="out of range"):
+            Date(9999, 12, 31).next_business_day()
+        with pytest.raises(ValueError, match="out of range"):
+            Date(1, 1, 1).previous_business_day()
+        with pytest.raises(ValueError, match="out of range"):
+            Date(2021, 1, 1).add_business_days(1 << 80)
+        assert Date(9999, 12, 30).next_business_day() == Date(9999, 12, 31)
+        assert Date(1, 1, 2).previous_business_day() == Date(1, 1, 1)
+
+
+class TestDaysOfWeek:
+    def test_valid(self):
+        dates = [Date(2021, 1, 2), Date(2021, 1, 4), Date(1, 1, 1)]
+        assert Date.days_of_week(dates) == [SATURDAY, MONDAY, MONDAY]
+        assert Date.days_of_week(iter(dates)) == [
+            d.day_of_week() for d in dates
+        ]
+        assert Date.days_of_week([]) == []
+
+    def test_matches_day_of_week(self):
+        dates = Date.from_ordinals(range(737_000, 737_100))
+        assert Date.days_of_week(dates) == [d.day_of_week() for d in dates]
+
+    def test_invalid_element(self):
+        with pytest.raises(
+            TypeError, match="Expected Date at index 1, got date"
+        ):
+            Date.days_of_week(
+                [Date(2021, 1, 2), py_date(2021, 1, 2)]  # type: ignore
+            )
+        with pytest.raises(TypeError, match="index 0, got NoneType"):
+            Date.days_of_week([None])  # type: ignore[list-item]
+
+
+@pytest.mark.parametrize(
+    "d, expected",
+    [
+        (Date(2021, 1, 1), 1),
+        (Date(2021, 2, 1), 32),
+        (Date(2021, 12, 31), 365),
+        (Date(2020, 12, 31), 366),
+        (Date(2020, 3, 1), 61),
+    ],
+)
+def test_day_of_year(d, expected):
+    assert d.day_of_year() == expected
+
+
+@pytest.mark.parametrize(
+    "d, expected",
+    [
+        (Date(2021, 1, 4), (2021, 1)),
+        (Date(2021, 1, 3), (2020, 53)),
+        (Date(2024, 12, 30), (2025, 1)),
+        (Date(2021, 6, 15), (2021, 24)),
+    ],
+)
+def test_iso_week(d, expected):
+    assert d.iso_week() == expected
+
+
+class TestFromIsoCalendar:
+    @pytest.mark.parametrize(
+        "args, expected",
+        [
+            ((2021, 1, 1), Date(2021, 1, 4)),
+            ((2020, 53, 7), Date(2021, 1, 3)),
+            ((2025, 1, 1), Date(2024, 12, 30)),
+            ((2021, 24, 2), Date(2021, 6, 15)),
+            ((2015, 53, 5), Date(2016, 1, 1)),
+            ((1, 1, 1), Date(1, 1, 1)),
+            ((9999, 52, 5), Date(9999, 12, 31)),
+        ],
+    )
+    def test_valid(self, args, expected):
+        assert Date.from_iso_calendar(*args) == expected
+        assert expected.iso_week() == args[:2]
+        assert expected.day_of_week() == args[2]
+
+    @pytest.mark.parametrize(
+        "args, msg",
+        [
+            ((2021, 53, 1), "week 53 is out of range for ISO year 2021"),
+            ((2021, 0, 1), "week 0 is out of range"),
+            ((2021, 1, 0), "weekday 0 is out of range"),
+            ((2021, 1, 8), "weekday 8 is out of range"),
+            ((0, 1, 1), "year 0 is out of range"),
+            ((10_000, 1, 1), "year 10000 is out of range"),
+            ((9999, 52, 6), "out of range"),
+        ],
+    )
+    def test_invalid(self, args, msg):
+        with pytest.raises(ValueError, match=re.escape(msg)):
+            Date.from_iso_calendar(*args)
+
+    @given(integers(MIN_YEAR, MAX_YEAR), integers(1, 53), integers(1, 7))
+    def test_matches_stdlib(self, year, week, weekday):
+        try:
+            expected = py_date.fromisocalendar(year, week, weekday)
+        except ValueError:
+            with pytest.raises(ValueError):
+                Date.from_iso_calendar(year, week, weekday)
+        else:
+            assert Date.from_iso_calendar(year, week, weekday) == Date(
+                expected.year, expected.month, expected.day
+            )
+
+
+class TestQuarter:
+    @pytest.mark.parametrize(
+        "month, expected",
+        [(1, 1), (3, 1), (4, 2), (6, 2), (7, 3), (9, 3), (10, 4), (12, 4)],
+    )
+    def test_quarter(self, month, expected):
+        assert Date(2021, month, 15).quarter() == expected
+        assert Date(2021, month, 15).fiscal_quarter(1) == expected
+
+    @pytest.mark.parametrize(
+        "d, start_month, expected",
+        [
+            (Date(2021, 4, 1), 4, 1),
+            (Date(2021, 3, 31), 4, 4),
+            (Date(2021, 12, 31), 4, 3),
+            (Date(2021, 1, 1), 4, 4),
+            (Date(2021, 10, 1), 10, 1),
+            (Date(2021, 3, 31), 10, 2),
+            (Date(2021, 9, 30), 10, 4),
+            (Date(2021, 2, 1), 2, 1),
+            (Date(2021, 1, 31), 2, 4),
+            (Date(2021, 1, 31), 12, 1),
+            (Date(2021, 12, 1), 12, 1),
+            (Date(2021, 11, 30), 12, 4),
+        ],
+    )
+    def test_fiscal_quarter(self, d, start_month, expected):
+        assert d.fiscal_quarter(start_month) == expected
+
+    @pytest.mark.parametrize("start_month", [0, 13, -1])
+    def test_fiscal_quarter_invalid(self, start_month):
+        with pytest.raises(ValueError, match="start_month"):
+            Date(2021, 1, 1).fiscal_quarter(start_month)
+
+    @pytest.mark.parametrize(
+        "d, start, end",
+        [
+            (Date(2021, 1, 1), Date(2021, 1, 1), Date(2021, 3, 31)),
+            (Date(2021, 3, 31), Date(2021, 1, 1), Date(2021, 3, 31)),
+            (Date(2021, 5, 17), Date(2021, 4, 1), Date(2021, 6, 30)),
+            (Date(2021, 8, 31), Date(2021, 7, 1), Date(2021, 9, 30)),
+            (Date(2021, 11, 2), Date(2021, 10, 1), Date(2021, 12, 31)),
+            (Date(1, 1, 1), Date(1, 1, 1), Date(1, 3, 31)),
+            (Date(9999, 12, 31), Date(9999, 10, 1), Date(9999, 12, 31)),
+        ],
+    )
+    def test_quarter_start_end(self, d, start, end):
+        assert d.quarter_start() == start
+        assert d.quarter_end() == end
+
+
+@pytest.mark.parametrize(
+    "d, num_days",
+    [
+        (Date(2021, 1, 15), 31),
+        (Date(2021, 2, 1), 28),
+        (Date(2020, 2, 29), 29),
+        (Date(2021, 4, 30), 30),
+    ],
+)
+def test_iter_month(d, num_days):
+    dates = list(d.iter_month())
+    assert len(dates) == num_days
+    assert dates[0] == Date(d.year, d.month, 1)
+    assert dates[-1] == Date(d.year, d.month, num_days)
+    assert all(b - a == DateDelta(days=1) for a, b in zip(dates, dates[1:]))
+
+
+@pytest.mark.parametrize(
+    "d1, d2, expected",
+    [
+        (Date(2021, 1, 2), Date(2021, 1, 2), 0),
+        (Date(2021, 1, 2), Date(2020, 12, 25), 8),
+        (Date(2020, 2, 28), Date(2020, 3, 1), 2),
+        (Date(2021, 2, 28), Date(2021, 3, 1), 1),
+        (Date(1, 1, 1), Date(9999, 12, 31), 3_652_058),
+    ],
+)
+def test_interval_days(d1, d2, expected):
+    assert d1.interval_days(d2) == expected
+    assert d2.interval_days(d1) == expected
+
+
+def test_interval_days_invalid_type():
+    with pytest.raises(TypeError, match="Expected Date"):
+        Date(2021, 1, 2).interval_days(
+            py_date(2021, 1, 1)  # type: ignore[arg-type]
+        )
+
+
+class TestAgeInYears:
+    @pytest.mark.parametrize(
+        "birth, as_of, expected",
+        [
+            (Date(2000, 5, 10), Date(2000, 5, 10), 0),
+            (Date(2000, 5, 10), Date(2001, 5, 9), 0),
+            (Date(2000, 5, 10), Date(2001, 5, 10), 1),
+            (Date(2000, 12, 31), Date(2001, 1, 1), 0),
+            (Date(2000, 12, 31), Date(2001, 12, 30), 0),
+            (Date(2000, 12, 31), Date(2001, 12, 31), 1),
+            (Date(2000, 1, 1), Date(2000, 12, 31), 0),
+            (Date(1, 1, 1), Date(9999, 12, 31), 9998),
+        ],
+    )
+    def test_basic(self, birth, as_of, expected):
+        assert birth.age_in_years(as_of) == expected
+
+    @pytest.mark.parametrize(
+        "as_of, expected",
+        [
+            (Date(2001, 2, 28), 0),
+            (Date(2001, 3, 1), 1),
+            (Date(2003, 2, 28), 2),
+            (Date(2003, 3, 1), 3),
+            (Date(2004, 2, 28), 3),
+            (Date(2004, 2, 29), 4),
+        ],
+    )
+    def test_leap_day_birthday(self, as_of, expected):
+        assert Date(2000, 2, 29).age_in_years(as_of) == expected
+
+    def test_leap_day_as_of(self):
+        assert Date(2001, 3, 1).age_in_years(Date(2004, 2, 29)) == 2
+        assert Date(2001, 2, 28).age_in_years(Date(2004, 2, 29)) == 3
+
+    def test_before_start(self):
+        with pytest.raises(ValueError, match="before start date"):
+            Date(2000, 5, 10).age_in_years(Date(2000, 5, 9))
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError, match="Expected Date"):
+            Date(2000, 5, 10).age_in_years(
+                py_date(2021, 1, 1)  # type: ignore[arg-type]
+            )
+
+
+class TestWeekStartEnd:
+    @pytest.mark.parametrize(
+        "d, first_day, start",
+        [
+            (Date(2021, 1, 6), MONDAY, Date(2021, 1, 4)),
+            (Date(2021, 1, 4), MONDAY, Date(2021, 1, 4)),
+            (Date(2021, 1, 10), MONDAY, Date(2021, 1, 4)),
+            (Date(2021, 1, 6), SUNDAY, Date(2021, 1, 3)),
+            (Date(2021, 1, 9), SUNDAY, Date(2021, 1, 3)),
+            (Date(2021, 1, 10), SUNDAY, Date(2021, 1, 10)),
+            (Date(2021, 1, 6), THURSDAY, Date(2020, 12, 31)),
+            (Date(2021, 1, 6), SATURDAY, Date(2021, 1, 2)),
+        ],
+    )
+    def test_valid(self, d, first_day, start):
+        assert d.week_start(first_day) == start
+        assert d.week_end(first_day) == start.add(days=6)
+        assert start.day_of_week() == first_day
+
+    def test_default_monday(self):
+        d = Date(2021, 1, 6)
+        assert d.week_start() == d.week_start(MONDAY)
+        assert d.week_end() == d.week_end(MONDAY)
+
+    def test_calendar_edges(self):
+        # 0001-01-01 is a Monday
+        assert Date(1, 1, 1).week_start() == Date(1, 1, 1)
+        assert Date(9999, 12, 31).week_end(SATURDAY) == Date(9999, 12, 31)
+        with pytest.raises(ValueError, match="before 0001-01-01"):
+            Date(1, 1, 1).week_start(SUNDAY)
+        with pytest.raises(ValueError, match="after 9999-12-31"):
+            Date(9999, 12, 31).week_end(MONDAY)
+
+    @pytest.mark.parametrize("first_day", [0, 8])
+    def test_invalid_first_day(self, first_day):
+        with pytest.raises(ValueError, match="first_day"):
+            Date(2021, 1, 6).week_start(first_day)
+        with pytest.raises(ValueError, match="first_day"):
+            Date(2021, 1, 6).week_end(first_day)
+
+
+class TestCountWeekdays:
+    @pytest.mark.parametrize(
+        "start, end, weekday, expected",
+        [
+            (Date(2021, 1, 4), Date(2021, 1, 4), MONDAY, 0),
+            (Date(2021, 1, 4), Date(2021, 1, 5), MONDAY, 1),
+            (Date(2021, 1, 4), Date(2021, 1, 11), MONDAY, 1),
+            (Date(2021, 1, 4), Date(2021, 1, 12), MONDAY, 2),
+            (Date(2021, 1, 5), Date(2021, 1, 11), MONDAY, 0),
+            (Date(2021, 1, 1), Date(2021, 2, 1), MONDAY, 4),
+            (Date(2021, 1, 1), Date(2021, 2, 1), FRIDAY, 5),
+            (Date(2021, 1, 1), Date(2022, 1, 1), SUNDAY, 52),
+            (Date(2021, 1, 1), Date(2022, 1, 1), FRIDAY, 53),
+        ],
+    )
+    def test_valid(self, start, end, weekday, expected):
+        assert start.count_weekdays(end, weekday) == expected
+
+    def test_matches_iteration(self):
+        start = Date(2020, 2, 27)
+        for days_later in range(30):
+            end = start.add(days=days_later)
+            for weekday in range(1, 8):
+                expected = sum(
+                    1
+                    for n in range(days_later)
+                    if start.add(days=n).day_of_week() == weekday
+                )
+                assert start.count_weekdays(end, weekday) == expected
+
+    def test_end_before_start(self):
+        with pytest.raises(ValueError, match="before start"):
+            Date(2021, 1, 2).count_weekdays(Date(2021, 1, 1), MONDAY)
+
+    @pytest.mark.parametrize("weekday", [0, 8, -1])
+    def test_invalid_weekday(self, weekday):
+        with pytest.raises(ValueError, match="weekday"):
+            Date(2021, 1, 1).count_weekdays(Date(2021, 2, 1), weekday)
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError, match="Expected Date"):
+            Date(2021, 1, 1).count_weekdays(
+                py_date(2021, 2, 1), MONDAY  # type: ignore[arg-type]
+            )
+
+
+class TestIterWeekdays:
+    def test_exclusive(self):
+        it = Date(2021, 1, 1).iter_weekdays(Date(2021, 1, 18), MONDAY)
+        assert list(it) == [Date(2021, 1, 4), Date(2021, 1, 11)]
+
+    def test_inclusive(self):
+        it = Date(2021, 1, 1).iter_weekdays(
+            Date(2021, 1, 18), MONDAY, inclusive=True
+        )
+        assert list(it) == [
+            Date(2021, 1, 4),
+            Date(2021, 1, 11),
+            Date(2021, 1, 18),
+        ]
+
+    def test_start_matches(self):
+        it = Date(2021, 1, 4).iter_weekdays(Date(2021, 1, 12), MONDAY)
+        assert list(it) == [Date(2021, 1, 4), Date(2021, 1, 11)]
+
+    def test_empty(self):
+        d = Date(2021, 1, 4)
+        assert list(d.iter_weekdays(d, MONDAY)) == []
+        assert list(d.iter_weekdays(d, MONDAY, inclusive=True)) == [d]
+        assert list(d.iter_weekdays(d, TUESDAY, inclusive=True)) == []
+
+    def test_calendar_edges(self):
+        assert list(
+            Date(9999, 12, 20).iter_weekdays(
+                Date(9999, 12, 31), FRIDAY, inclusive=True
+            )
+        ) == [Date(9999, 12, 24), Date(9999, 12, 31)]
+        assert next(Date(1, 1, 1).iter_weekdays(Date(1, 2, 1), MONDAY)) == (
+            Date(1, 1, 1)
+        )
+
+    def test_matches_count_weekdays(self):
+        start = Date(2020, 2, 27)
+        for days_later in range(30):
+            end = start.add(days=days_later)
+            for weekday in range(1, 8):
+                dates = list(start.iter_weekdays(end, weekday))
+                assert len(dates) == start.count_weekdays(end, weekday)
+                assert all(d.day_of_week() == weekday for d in dates)
+                assert all(start <= d < end for d in dates)
+
+    def test_errors_raised_eagerly(self):
+        with pytest.raises(ValueError, match="before start"):
+            Date(2021, 1, 2).iter_weekdays(Date(2021, 1, 1), MONDAY)
+        with pytest.raises(ValueError, match="weekday"):
+            Date(2021, 1, 1).iter_weekdays(Date(2021, 2, 1), 8)
+        with pytest.raises(TypeError, match="Expected Date"):
+            Date(2021, 1, 1).iter_weekdays(
+                py_date(2021, 2, 1), MONDAY  # type: ignore[arg-type]
+            )
+
+
+class TestBusinessDaysBetween:
+    @pytest.mark.parametrize(
+        "start, end, expected",
+        [
+            (Date(2021, 12, 20), Date(2021, 12, 20), 0),
+            (Date(2021, 12, 20), Date(2021, 12, 21), 1),
+            (Date(2021, 12, 20), Date(2021, 12, 25), 5),
+            (Date(2021, 12, 20), Date(2021, 12, 27), 5),
+            (Date(2021, 12, 20), Date(2021, 12, 28), 6),
+            (Date(2021, 12, 25), Date(2021, 12, 27), 0),
+            (Date(2021, 12, 24), Date(2021, 12, 28), 2),
+            (Date(2021, 1, 1), Date(2022, 1, 1), 261),
+            (Date(1, 1, 1), Date(9999, 12, 31), 2_608_614),
+        ],
+    )
+    def test_without_holidays(self, start, end, expected):
+        assert start.business_days_between(end) == expected
+        assert start.business_days_between(end) == sum(
+            start.count_weekdays(end, wd) for wd in range(MONDAY, SATURDAY)
+        )
+
+    def test_week_with_holiday(self):
+        start, end = Date(2021, 12, 20), Date(2021, 12, 27)
+        assert start.business_days_between(end, [Date(2021, 12, 24)]) == 4
+
+    def test_holidays_ignored(self):
+        start, end = Date(2021, 12, 20), Date(2021, 12, 27)
+        holidays = [
+            # weekend
+            Date(2021, 12, 25),
+            Date(2021, 12, 26),
+            # outside the range
+            Date(2021, 12, 17),
+            Date(2021, 12, 27),
+        ]
+        assert start.business_days_between(end, holidays) == 5
+
+    def test_duplicate_holidays(self):
+        start, end = Date(2021, 12, 20), Date(2021, 12, 27)
+        holidays = iter([Date(2021, 12, 24), Date(2021, 12, 24)])
+        assert start.business_days_between(end, holidays) == 4
+
+    def test_matches_iteration(self):
+        start = Date(2020, 2, 27)
+        holidays = {Date(2020, 3, 2), Date(2020, 3, 7), Date(2020, 3, 13)}
+        for days_later in range(30):
+            end = start.add(days=days_later)
+            expected = sum(
+                1
+                for n in range(days_later)
+                if start.add(days=n).day_of_week() < SATURDAY
+                and start.add(days=n) not in holidays
+            )
+            assert start.business_days_between(end, holidays) == expected
+
+    def test_end_before_start(self):
+        with pytest.raises(ValueError, match="before start"):
+            Date(2021, 1, 2).business_days_between(Date(2021, 1, 1))
+
+    def test_invalid_type(self):
+        with pytest.raises(TypeError, match="Expected Date"):
+            Date(2021, 1, 1).business_days_between(
+                py_date(2021, 2, 1)  # type: ignore[arg-type]
+            )
+
+    def test_invalid_holiday(self):
+        with pytest.raises(TypeError, match="holiday.*date"):
+            Date(2021, 1, 1).business_days_between(
+                Date(2021, 2, 1),
+                [Date(2021, 1, 4), py_date(2021, 1, 5)],  # type: ignore
+            )
+
+
 def test_pickling():
     d = Date(2021, 1, 2)
     dumped = pickle.dumps(d)
@@ -344,6 +1451,28 @@ def test_pickling():
     assert pickle.loads(dumped) == d
 
 
+@pytest.mark.parametrize("protocol", range(pickle.HIGHEST_PROTOCOL + 1))
+@pytest.mark.parametrize(
+    "d", [Date(2021, 1, 2), Date(1, 1, 1), Date(9999, 12, 31)]
+)
+def test_pickling_protocols(d, protocol):
+    dumped = pickle.dumps(d, protocol)
+    assert len(dumped) < len(pickle.dumps(d._py_date, protocol)) + 10
+    unpickled = pickle.loads(dumped)
+    assert type(unpickled) is Date
+    assert unpickled == d
+
+
+@pytest.mark.parametrize(
+    "d", [Date(2021, 1, 2), Date(1, 1, 1), Date(9999, 12, 31)]
+)
+def test_getnewargs(d):
+    args = d.__getnewargs__()
+    assert args == (d.year, d.month, d.day)
+    assert type(args) is tuple
+    assert Date(*args) == d
+
+
 def test_unpickle_compatibility():
     dumped = (
         b"\x80\x04\x95'\x00\x00\x00\x00\x00\x00\x00\x8c\x08whenever\x94\x8c\x0b_unp"
@@ -358,6 +1487,155 @@ def test_copy():
     assert deepcopy(d) is d
 
 
+class TestClamp:
+    lower = Date(2020, 1, 1)
+    upper = Date(2020, 12, 31)
+
+    @pytest.mark.parametrize(
+        "d, expected",
+        [
+            (Date(2019, 12, 31), lower),
+            (Date(2020, 1, 1), lower),
+            (Date(2020, 6, 1), Date(2020, 6, 1)),
+            (Date(2020, 12, 31), upper),
+            (Date(2021, 1, 1), upper),
+        ],
+    )
+    def test_valid(self, d, expected):
+        assert d.clamp(self.lower, self.upper) == expected
+
+    def test_returns_bounds_and_self(self):
+        d = Date(2020, 6, 1)
+        assert d.clamp(self.lower, self.upper) is d
+        assert Date(1900, 1, 1).clamp(self.lower, self.upper) is self.lower
+        assert d.clamp(d, d) is d
+
+    def test_bounds_reversed(self):
+        with pytest.raises(ValueError, match="after upper bound"):
+            self.lower.clamp(self.upper, self.lower)
+
+    def test_wrong_type(self):
+        with pytest.raises(TypeError, match="Date"):
+            self.lower.clamp(
+                self.lower, py_date(2021, 1, 1)  # type: ignore[arg-type]
+            )
+        with pytest.raises(TypeError, match="Date"):
+            self.lower.clamp(None, self.upper)  # type: ignore[arg-type]
+
+
+class TestIsBetween:
+    start = Date(2021, 1, 1)
+    end = Date(2021, 1, 31)
+
+    @pytest.mark.parametrize(
+        "d, inclusive, expected",
+        [
+            (Date(2021, 1, 15), "both", True),
+            (Date(2021, 1, 15), "neither", True),
+            (Date(2021, 1, 1), "both", True),
+            (Date(2021, 1, 1), "left", True),
+            (Date(2021, 1, 1), "right", False),
+            (Date(2021, 1, 1), "neither", False),
+            (Date(2021, 1, 31), "both", True),
+            (Date(2021, 1, 31), "left", False),
+            (Date(2021, 1, 31), "right", True),
+            (Date(2021, 1, 31), "neither", False),
+            (Date(2020, 12, 31), "both", False),
+            (Date(2021, 2, 1), "both", False),
+        ],
+    )
+    def test_valid(self, d, inclusive, expected):
+        assert d.is_between(self.start, self.end, inclusive) is expected
+
+    def test_default_inclusive(self):
+        assert self.start.is_between(self.start, self.end)
+        assert self.end.is_between(self.start, self.end)
+
+    def test_empty_range(self):
+        assert self.start.is_between(self.start, self.start)
+        assert not self.start.is_between(self.start, self.start, "left")
+
+    def test_start_after_end(self):
+        with pytest.raises(ValueError, match="after end"):
+            self.start.is_between(self.end, self.start)
+
+    def test_invalid_inclusive(self):
+        with pytest.raises(ValueError, match="inclusive"):
+            self.start.is_between(
+                self.start, self.end, "all"  # type: ignore[arg-type]
+            )
+
+    def test_wrong_type(self):
+        with pytest.raises(TypeError, match="Date"):
+            self.start.is_between(
+                py_date(2021, 1, 1), self.end  # type: ignore[arg-type]
+            )
+
+
+def test_sizeof():
+    d = Date(2021, 1, 2)
+    # sys.getsizeof() isn't available on PyPy, so call __sizeof__() directly
+    assert d.__sizeof__() == object.__sizeof__(d) + d.py_date().__sizeof__()
+    assert d.__sizeof__() > py_date(2021, 1, 2).__sizeof__()
+
+
+class TestSubclass:
+    class MyDate(Date):
+        pass
+
+    def test_init(self):
+        d = self.MyDate(2021, 1, 2)
+        assert type(d) is self.MyDate
+        assert d == Date(2021, 1, 2)
+
+    def test_alternative_constructors(self):
+        MyDate = self.MyDate
+        assert type(MyDate.from_py_date(py_date(2021, 1, 2))) is MyDate
+        assert type(MyDate.from_canonical_format("2021-01-02")) is MyDate
+        assert type(MyDate.from_ordinals([1])[0]) is MyDate
+        assert type(MyDate.from_packed(132_448_514)) is MyDate
+        assert type(MyDate.from_days_since_epoch(0)) is MyDate
+        assert type(MyDate.from_year_day(2021, 1)) is MyDate
+
+    @pytest.mark.parametrize(
+        "method, args",
+        [
+            ("tomorrow", ()),
+            ("yesterday", ()),
+            ("succ_or_none", ()),
+            ("pred_or_none", ()),
+            ("with_year", (2022,)),
+            ("with_month", (3,)),
+            ("with_day", (5,)),
+            ("week_start", ()),
+            ("week_end", ()),
+            ("quarter_start", ()),
+            ("quarter_end", ()),
+        ],
+    )
+    def test_methods(self, method, args):
+        d = self.MyDate(2021, 1, 2)
+        assert type(getattr(d, method)(*args)) is self.MyDate
+
+    def test_arithmetic(self):
+        d = self.MyDate(2021, 1, 2)
+        assert type(d.add(days=1)) is self.MyDate
+        assert type(d.add(months=1)) is self.MyDate
+        assert type(d.subtract(years=1, days=3)) is self.MyDate
+        assert type(d + DateDelta(weeks=2)) is self.MyDate
+        assert type(d - DateDelta(months=2)) is self.MyDate
+        assert type(d + timedelta(days=1)) is self.MyDate
+        assert type(d - timedelta(days=1)) is self.MyDate
+        assert all(type(x) is self.MyDate for x in d.iter_month())
+
+    def test_results_equal_to_base_class(self):
+        d = self.MyDate(2021, 1, 31)
+        base = Date(2021, 1, 31)
+        assert d.tomorrow() == base.tomorrow()
+        assert d.add(months=1) == base.add(months=1)
+        assert d.week_start() == base.week_start()
+
+
 def test_weakref():
     d = Date(2021, 1, 2)
     ref = weakref.ref(d)
@@ -396,3 +1674,115 @@ def test_from_common_iso8601_invalid(s):
         match=r"Could not parse.*ISO 8601.*" + repr(s),
     ):
         Date.from_common_iso8601(s)
+
+
+@pytest.mark.parametrize(
+    "d, expected",
+    [
+        (Date(2021, 1, 1), "Fri, 01 Jan 2021"),
+        (Date(2020, 2, 29), "Sat, 29 Feb 2020"),
+        (Date(2021, 12, 31), "Fri, 31 Dec 2021"),
+        (Date(1, 1, 1), "Mon, 01 Jan 0001"),
+    ],
+)
+def test_rfc2822(d, expected):
+    assert d.rfc2822() == expected
+    assert Date.from_rfc2822(expected) == d
+
+
+@pytest.mark.parametrize(
+    "s",
+    [
+        "Sat, 01 Jan 2021",  # wrong weekday
+        "Fri, 1 Jan 2021",  # no padding
+        "01 Jan 2021",  # no weekday
+        "Fri, 01 Jan 2021 00:00:00 GMT",  # with time
+        "Fri, 01 Jan 21",  # two-digit year
+        "fri, 01 jan 2021",  # lowercase
+        "Tue, 30 Feb 2021",  # nonexistent date
+        "Fri,  01 Jan 2021",  # extra space
+        "",
+    ],
+)
+def test_from_rfc2822_invalid(s):
+    with pytest.raises(ValueError, match="Could not parse.*RFC 2822"):
+        Date.from_rfc2822(s)
+
+
+@pytest.mark.parametrize(
+    "string, fmt, expected",
+    [
+        ("2021-01-02", "%Y-%m-%d", Date(2021, 1, 2)),
+        ("02/01/2021", "%d/%m/%Y", Date(2021, 1, 2)),
+        ("2021-032", "%Y-%j", Date(2021, 2, 1)),
+        ("2020-366", "%Y-%j", Date(2020, 12, 31)),
+        ("%2021%0102", "%%%Y%%%m%d", Date(2021, 1, 2)),
+    ],
+)
+def test_strptime(string, fmt, expected):
+    assert Date.strptime(string, fmt) == expected
+
+
+@pytest.mark.parametrize(
+    "string, fmt",
+    [
+        ("2021-01-02", "%Y-%m"),
+        ("2021-02-30", "%Y-%m-%d"),
+        ("2021-366", "%Y-%j"),
+        ("2021", "%Y-%m-%d"),
+        ("2021-000", "%Y-%j"),
+        ("20210102", "%Y-%m-%d"),
+    ],
+)
+def test_strptime_no_match(string, fmt):
+    with pytest.raises(ValueError):
+        Date.strptime(string, fmt)
+
+
+@pytest.mark.parametrize(
+    "fmt", ["%Y-%m-%d %H", "%Y-%b-%d", "%Y-%m-%d%", "%Y-%m-%d-%d"]
+)
+def test_strptime_unsupported_directive(fmt):
+    with pytest.raises(ValueError, match="Unsupported or repeated"):
+        Date.strptime("2021-01-02", fmt)
+
+
+@pytest.mark.parametrize("fmt", ["%Y-%j-%m", "%Y-%j-%d"])
+def test_strptime_conflicting_directives(fmt):
+    with pytest.raises(ValueError, match="cannot combine"):
+        Date.strptime("2021-032-01", fmt)
+
+
+class TestProperties:
+    @given(ordinals)
+    def test_ordinal_roundtrip(self, n):
+        [d] = Date.from_ordinals([n])
+        assert Date(d.year, d.month, d.day) == d
+        assert Date.from_ordinals([n - d.day_of_year() + 1]) == [
+            Date(d.year, 1, 1)
+        ]
+
+    @given(ordinals, integers(-3_652_059, 3_652_059))
+    def test_add_days_inverse(self, n, k):
+        assume(1 <= n + k <= py_date.max.toordinal())
+        [d] = Date.from_ordinals([n])
+        shifted = d.add(days=k)
+        assert Date.from_ordinals([n + k]) == [shifted]
+        assert shifted.add(days=-k) == d
+        assert shifted - DateDelta(days=k) == d
+
+    @given(ordinals, ordinals)
+    def test_ordering_matches_ordinals(self, n1, n2):
+        d1, d2 = Date.from_ordinals([n1, n2])
+        assert (d1 < d2) == (n1 < n2)
+        assert (d1 <= d2) == (n1 <= n2)
+        assert (d1 > d2) == (n1 > n2)
+        assert (d1 >= d2) == (n1 >= n2)
+        assert (d1 == d2) == (n1 == n2)
+        if d1 == d2:
+            assert hash(d1) == hash(d2)
+
+    @given(ordinals, ordinals)
+    def test_subtract_dates(self, n1, n2):
+        d1, d2 = Date.from_ordinals([n1, n2])
+        assert d2 + (d1 - d2) == d1
@@ -0,0 +1,174 @@
+import pickle
+import weakref
+from copy import copy, deepcopy
+
+import pytest
+from hypothesis import given
+from hypothesis.strategies import integers
+
+from whenever import Date, DateRange, NaiveDateTime
+
+
+class TestInit:
+
+    def test_basics(self):
+        r = DateRange(Date(2021, 1, 1), Date(2021, 2, 1), 7)
+        assert r.start == Date(2021, 1, 1)
+        assert r.stop == Date(2021, 2, 1)
+        assert r.step == 7
+
+    def test_default_step(self):
+        assert DateRange(Date(2021, 1, 1), Date(2021, 2, 1)).step == 1
+
+    @pytest.mark.parametrize(
+        "start, stop",
+        [
+            (Date(2021, 1, 1), "2021-02-01"),
+            (NaiveDateTime(2021, 1, 1), Date(2021, 2, 1)),
+            (None, Date(2021, 2, 1)),
+        ],
+    )
+    def test_invalid_bounds(self, start, stop):
+        with pytest.raises(TypeError, match="Date"):
+            DateRange(start, stop)
+
+    def test_invalid_step(self):
+        with pytest.raises(ValueError, match="zero"):
+            DateRange(Date(2021, 1, 1), Date(2021, 2, 1), 0)
+        with pytest.raises(TypeError):
+            DateRange(
+                Date(2021, 1, 1), Date(2021, 2, 1), 1.5  # type: ignore
+            )
+
+
+@pytest.mark.parametrize(
+    "r, expected",
+    [
+        (
+            DateRange(Date(2021, 1, 1), Date(2021, 1, 4)),
+            [Date(2021, 1, 1), Date(2021, 1, 2), Date(2021, 1, 3)],
+        ),
+        (
+            DateRange(Date(2021, 1, 1), Date(2021, 1, 15), 7),
+            [Date(2021, 1, 1), Date(2021, 1, 8)],
+        ),
+        (
+            DateRange(Date(2021, 1, 1), Date(2021, 1, 16), 7),
+            [Date(2021, 1, 1), Date(2021, 1, 8), Date(2021, 1, 15)],
+        ),
+        (
+            DateRange(Date(2021, 1, 3), Date(2021, 1, 1), -1),
+            [Date(2021, 1, 3), Date(2021, 1, 2)],
+        ),
+        (
+            DateRange(Date(2020, 2, 27), Date(2020, 3, 2)),
+            [
+                Date(2020, 2, 27),
+                Date(2020, 2, 28),
+                Date(2020, 2, 29),
+                Date(2020, 3, 1),
+            ],
+        ),
+        (DateRange(Date(2021, 1, 1), Date(2021, 1, 1)), []),
+        (DateRange(Date(2021, 1, 2), Date(2021, 1, 1)), []),
+        (DateRange(Date(2021, 1, 1), Date(2021, 1, 2), -1), []),
+        (
+            DateRange(Date(9999, 12, 30), Date(9999, 12, 31)),
+            [Date(9999, 12, 30)],
+        ),
+    ],
+)
+def test_iter_and_len(r, expected):
+    assert list(r) == expected
+    assert len(r) == len(expected)
+    assert bool(r) is bool(expected)
+
+
+class TestContains:
+
+    def test_aligned(self):
+        r = DateRange(Date(2021, 1, 1), Date(2021, 2, 1), 7)
+        assert Date(2021, 1, 1) in r
+        assert Date(2021, 1, 8) in r
+        assert Date(2021, 1, 29) in r
+        assert Date(2021, 1, 2) not in r
+        assert Date(2021, 1, 28) not in r
+
+    def test_bounds(self):
+        r = DateRange(Date(2021, 1, 1), Date(2021, 1, 15), 7)
+        assert Date(2021, 1, 15) not in r
+        assert Date(2020, 12, 25) not in r
+        assert Date(2021, 1, 22) not in r
+
+    def test_negative_step(self):
+        r = DateRange(Date(2021, 1, 10), Date(2021, 1, 1), -3)
+        assert Date(2021, 1, 10) in r
+        assert Date(2021, 1, 4) in r
+        assert Date(2021, 1, 1) not in r
+        assert Date(2021, 1, 5) not in r
+        assert Date(2021, 1, 13) not in r
+
+    def test_empty(self):
+        r = DateRange(Date(2021, 1, 1), Date(2021, 1, 1))
+        assert Date(2021, 1, 1) not in r
+
+    def test_large_range(self):
+        r = DateRange(Date(1, 1, 1), Date(9999, 12, 31), 2)
+        assert Date(1, 1, 3) in r
+        assert Date(9999, 12, 30) not in r
+        assert len(r) == 1_826_029
+
+    @pytest.mark.parametrize(
+        "value",
+        [NaiveDateTime(2021, 1, 1), "2021-01-01", None, 738_000],
+    )
+    def test_invalid_type(self, value):
+        r = DateRange(Date(2021, 1, 1), Date(2021, 2, 1))
+        with pytest.raises(TypeError, match="Expected Date"):
+            value in r
+
+    @given(integers(0, 100), integers(0, 100), integers(1, 10))
+    def test_matches_iteration(self, length, offset, step):
+        start = Date(2021, 1, 1)
+        r = DateRange(start, start.add(days=length), step)
+        d = start.add(days=offset)
+        assert (d in r) is (d in list(r))
+
+
+def test_equality():
+    r = DateRange(Date(2021, 1, 1), Date(2021, 2, 1), 7)
+    same = DateRange(Date(2021, 1, 1), Date(2021, 2, 1), 7)
+    assert r == same
+    assert hash(r) == hash(same)
+    assert r != DateRange(Date(2021, 1, 1), Date(2021, 2, 1))
+    assert r != DateRange(Date(2021, 1, 2), Date(2021, 2, 1), 7)
+    assert r != DateRange(Date(2021, 1, 1), Date(2021, 2, 2), 7)
+    assert r != (Date(2021, 1, 1), Date(2021, 2, 1), 7)  # type: ignore
+
+
+def test_repr():
+    assert (
+        repr(DateRange(Date(2021, 1, 1), Date(2021, 2, 1)))
+        == "DateRange(2021-01-01, 2021-02-01)"
+    )
+    assert (
+        repr(DateRange(Date(2021, 1, 1), Date(2021, 2, 1), -2))
+        == "DateRange(2021-01-01, 2021-02-01, step=-2)"
+    )
+
+
+def test_pickle():
+    r = DateRange(Date(2021, 1, 1), Date(2021, 2, 1), 7)
+    assert pickle.loads(pickle.dumps(r)) == r
+
+
+def test_copy():
+    r = DateRange(Date(2021, 1, 1), Date(2021, 2, 1), 7)
+    assert copy(r) is r
+    assert deepcopy(r) is r
+
+
+def test_weakref():
+    r = DateRange(Date(2021, 1, 1), Date(2021, 2, 1), 7)
+    ref = weakref.ref(r)
+    assert ref() == r
@@ -0,0 +1,106 @@
+import pytest
+from freezegun import freeze_time
+
+import whenever
+from whenever import (
+    MAX_YEAR,
+    MIN_YEAR,
+    Date,
+    OffsetDateTime,
+    TimeDelta,
+    UTCDateTime,
+    hours,
+    minutes,
+    parse_common_iso8601,
+    today_in_offset,
+)
+
+
+def test_version():
+    assert isinstance(whenever.__version__, str)
+    assert whenever.__version__.count(".") == 2
+
+
+def test_year_range():
+    assert (MIN_YEAR, MAX_YEAR) == (1, 9999)
+    assert Date(MIN_YEAR, 1, 1) == Date.from_ordinals([1])[0]
+    assert UTCDateTime(MAX_YEAR, 12, 31, 23, 59, 59, 999_999) == (
+        UTCDateTime.MAX
+    )
+    with pytest.raises(ValueError, match="out of range"):
+        Date(MIN_YEAR - 1, 1, 1)
+    with pytest.raises(ValueError, match="out of range"):
+        Date(MAX_YEAR + 1, 1, 1)
+
+
+@pytest.mark.parametrize(
+    "s, expected",
+    [
+        ("2020-08-15", Date(2020, 8, 15)),
+        ("2020-08-15T23:12:00Z", UTCDateTime(2020, 8, 15, 23, 12)),
+        (
+            "2020-08-15T23:12:00.000123Z",
+            UTCDateTime(2020, 8, 15, 23, 12, microsecond=123),
+        ),
+        (
+            "2020-08-15T23:12:00+02:00",
+            OffsetDateTime(2020, 8, 15, 23, 12, offset=2),
+        ),
+        (
+            "2020-08-15T23:12:00+00:00",
+            OffsetDateTime(2020, 8, 15, 23, 12, offset=0),
+        ),
+    ],
+)
+def test_parse_common_iso8601(s, expected):
+    result = parse_common_iso8601(s)
+    assert type(result) is type(expected)
+    assert result == expected
+
+
+@pytest.mark.parametrize(
+    "s",
+    [
+        "",
+        "2020-08",
+        "2020-13-15",
+        "2020-08-15T23:12:00",
+        "2020-08-15T23:12:00z",
+        "2020-08-15 23:12:00Z",
+        "2020-08-15T23:12:00+02:00[Europe/Berlin]",
+        "23:12:00",
+        "P1D",
+    ],
+)
+def test_parse_common_iso8601_invalid(s):
+    with pytest.raises(ValueError, match="Could not parse.*common ISO 8601"):
+        parse_common_iso8601(s)
+
+
+class TestTodayInOffset:
+    @pytest.mark.parametrize(
+        "offset, expected",
+        [
+            (0, Date(2020, 8, 15)),
+            (1, Date(2020, 8, 16)),
+            (hours(5) + minutes(30), Date(2020, 8, 16)),
+            (-23, Date(2020, 8, 15)),
+            (hours(-23) - minutes(59), Date(2020, 8, 14)),
+        ],
+    )
+    @freeze_time("2020-08-15T23:12:09Z")
+    def test_frozen(self, offset, expected):
+        assert today_in_offset(offset) == expected
+
+    @pytest.mark.parametrize("offset", [0, 2, -5, TimeDelta(hours=-3)])
+    def test_matches_now(self, offset):
+        before = OffsetDateTime.now(offset).date()
+        today = today_in_offset(offset)
+        after = OffsetDateTime.now(offset).date()
+        assert today in (before, after)
+        assert type(today) is Date
+
+    @pytest.mark.parametrize("offset", [24, -24, hours(24), hours(-25)])
+    def test_invalid(self, offset):
+        with pytest.raises(ValueError):
+            today_in_offset(offset)
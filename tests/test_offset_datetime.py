@@ -805,6 +805,18 @@ def test_from_rfc3339_invalid():
         OffsetDateTime.from_rfc3339("2020-08-15T23:12-02:00")
 
 
+@pytest.mark.parametrize(
+    "s",
+    [
+        "2016-12-31T23:59:60Z",
+        "2016-12-31T23:59:60.5+02:00",
+    ],
+)
+def test_from_rfc3339_leap_second(s):
+    with pytest.raises(ValueError, match="Leap seconds are not supported"):
+        OffsetDateTime.from_rfc3339(s)
+
+
 @pytest.mark.parametrize(
     "d, expected",
     [
@@ -36,6 +36,23 @@ class TestInit:
         with pytest.raises(ValueError):
             Time(0, 0, 0, 1_000_000)
 
+    @pytest.mark.parametrize(
+        "kwargs, msg",
+        [
+            (dict(hour=24), "hour must be in 0..23"),
+            (dict(hour=-1), "hour must be in 0..23"),
+            (dict(minute=60), "minute must be in 0..59"),
+            (dict(minute=-1), "minute must be in 0..59"),
+            (dict(second=60), "second must be in 0..59"),
+            (dict(second=-1), "second must be in 0..59"),
+            (dict(microsecond=1_000_000), "microsecond must be in 0..999999"),
+            (dict(microsecond=-1), "microsecond must be in 0..999999"),
+        ],
+    )
+    def test_out_of_range_message(self, kwargs, msg):
+        with pytest.raises(ValueError, match=re.escape(msg)):
+            Time(**kwargs)
+
 
 @pytest.mark.parametrize(
     "t, expect",